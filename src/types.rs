@@ -118,6 +118,400 @@ pub struct SvTypeIntegral {
     pub value: Option<Vec<usize>>,
 }
 
+// Layout/`$bits` calculator for `SvTypeIntegral::value`.
+//
+// `SvTypeIntegral::value` documents a precise bit-packing scheme but leaves
+// readers to re-derive offsets by hand. `SvLayout` is the computed result of
+// that scheme for a given type and platform `usize` width, and
+// `LayoutCalculator` is how it's obtained.
+impl SvTypeIntegral {
+    // Total width in bits of a single packed value, i.e. the product of all
+    // packed dimension widths. A scalar (`packed=None`) is 1 bit.
+    fn packed_bits(&self) -> u64 {
+        dims_bits(&self.packed)
+    }
+
+    // Number of elements in the unpacked shape, i.e. the product of all
+    // unpacked dimension counts. `unpacked=None` is a single element.
+    fn unpacked_count(&self) -> u64 {
+        dims_bits(&self.unpacked)
+    }
+
+    // Total `$bits`, independent of any platform `usize` width. `None` if
+    // `sized=false`.
+    pub fn bits(&self) -> Option<u64> {
+        self.sized.then(|| self.packed_bits() * self.unpacked_count())
+    }
+}
+
+// Product of the bit-widths of a list of packed/unpacked dimensions, where
+// each `(u64, u64)` is an inclusive `[msb:lsb]`-style range in either order.
+// `None` (scalar) contributes a single bit/element, matching the doc comment
+// on `SvTypeIntegral::packed`/`unpacked`.
+fn dims_bits(dims: &Option<Vec<(u64, u64)>>) -> u64 {
+    match dims {
+        None => 1,
+        Some(dims) => dims_count(dims),
+    }
+}
+
+// Product of the element counts of a (non-optional) list of dimensions.
+fn dims_count(dims: &[(u64, u64)]) -> u64 {
+    dims.iter()
+        .map(|&(a, b)| if a >= b { a - b + 1 } else { b - a + 1 })
+        .product()
+}
+
+// The `(word_index, bit_offset)` location of a single bit within
+// `SvTypeIntegral::value`, plus (for fourstate types) the companion word
+// that holds its X/Z mask bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SvBitLocation {
+    pub word_index: usize,
+    pub bit_offset: u64,
+    pub mask_word_index: Option<usize>,
+}
+
+// The computed `$bits`/word layout of an `SvTypeIntegral` on a platform with
+// a given `usize` width. Obtained via `LayoutCalculator::layout`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SvLayout {
+    // Total object size, i.e. `$bits`.
+    pub bits: u64,
+
+    packed_bits: u64,
+    unpacked_count: u64,
+    fourstate: bool,
+    usize_bits: u64,
+
+    // `usize` words occupied by one unpacked element's packed value,
+    // doubled for fourstate.
+    words_per_element: usize,
+}
+
+impl SvLayout {
+    // Total number of `usize` words in `SvTypeIntegral::value`.
+    pub fn words(&self) -> usize {
+        self.words_per_element * self.unpacked_count as usize
+    }
+
+    // Locate the bit at `packed_bit` (0 = LSB of the packed value) within
+    // unpacked element `unpacked_index` (0 = first-declared element, as in
+    // the worked examples on `SvTypeIntegral::value`).
+    //
+    // Returns `None` if either index is out of range.
+    pub fn bit_location(&self, unpacked_index: u64, packed_bit: u64) -> Option<SvBitLocation> {
+        if unpacked_index >= self.unpacked_count || packed_bit >= self.packed_bits {
+            return None;
+        }
+
+        // Unpacked elements are stored with the first-declared element at
+        // the *highest* word offset, per the worked examples (e.g. example
+        // 13, where `{123, ...}`'s `123` ends up in the last two words).
+        let element_index = self.unpacked_count - 1 - unpacked_index;
+        let element_base = element_index as usize * self.words_per_element;
+
+        let value_word = (packed_bit / self.usize_bits) as usize;
+        let bit_offset = packed_bit % self.usize_bits;
+        let stride = if self.fourstate { 2 } else { 1 };
+        let word_index = element_base + value_word * stride;
+
+        Some(SvBitLocation {
+            word_index,
+            bit_offset,
+            mask_word_index: self.fourstate.then_some(word_index + 1),
+        })
+    }
+
+    // Build the layout of a single flattened packed value of `bits` wide,
+    // as used by packed aggregate types (see 7.2/7.3/7.4), which reuse this
+    // same word-packing scheme with no unpacked dimension of their own.
+    fn from_bits(bits: u64, fourstate: bool, usize_bits: u64) -> SvLayout {
+        let value_words = bits.div_ceil(usize_bits);
+        let words_per_element = (if fourstate { value_words * 2 } else { value_words }) as usize;
+        SvLayout {
+            bits,
+            packed_bits: bits,
+            unpacked_count: 1,
+            fourstate,
+            usize_bits,
+            words_per_element,
+        }
+    }
+}
+
+// Turns type shape + representation flags into concrete `$bits`/word
+// offsets and sizes, per the packing rules documented on
+// `SvTypeIntegral::value`.
+pub trait LayoutCalculator {
+    // Compute the layout for this type on a platform where `usize` is
+    // `usize_bits` bits wide. Returns `None` if `sized=false`, since
+    // `$bits` is then not computable.
+    fn layout(&self, usize_bits: u64) -> Option<SvLayout>;
+}
+
+impl LayoutCalculator for SvTypeIntegral {
+    fn layout(&self, usize_bits: u64) -> Option<SvLayout> {
+        if !self.sized {
+            return None;
+        }
+
+        let packed_bits = self.packed_bits();
+        let unpacked_count = self.unpacked_count();
+
+        // Each packed value is usize-aligned: a value wider than `usize`
+        // continues into the next word, and fourstate doubles the word
+        // count with even words holding 0/1 and odd words selecting X/Z.
+        // Multiple packed dimensions are flattened into one contiguous
+        // packed value (the product of their widths); a second or later
+        // packed dimension does not begin on a new word of its own.
+        let value_words = packed_bits.div_ceil(usize_bits);
+        let words_per_element = (if self.fourstate { value_words * 2 } else { value_words }) as usize;
+
+        Some(SvLayout {
+            bits: packed_bits * unpacked_count,
+            packed_bits,
+            unpacked_count,
+            fourstate: self.fourstate,
+            usize_bits,
+            words_per_element,
+        })
+    }
+}
+
+// A single bit of a 4-state value: `SvTypeIntegral::value`'s documented
+// encoding squashed to one of four states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SvBit {
+    Zero,
+    One,
+    X,
+    Z,
+}
+
+// An error parsing a sized/based literal with `SvTypeIntegral::from_literal`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SvLiteralError {
+    // Didn't look like `[size]'[s]base digits`.
+    Syntax(String),
+    // `b`/`o`/`d`/`h` expected.
+    UnknownBase(char),
+    // A digit wasn't valid in the literal's base.
+    InvalidDigit(char),
+    // Decimal (`'d`) literals can't contain `x`/`z` digits (LRM 5.7.1).
+    DecimalWithXZ,
+    // The decimal value doesn't fit in a `u128`, which is the largest value
+    // this parser can convert to binary.
+    ValueTooLarge,
+}
+
+impl SvTypeIntegral {
+    // Parse a standard SystemVerilog sized, based literal, e.g. `5'b01XZ0`,
+    // `45'd123`, `8'hZ`, `1'b0`, into the bit-packing scheme documented on
+    // `value`. `_` digit separators are allowed anywhere in the digits.
+    //
+    // The literal's size is required (unsized literals like `'h1` aren't
+    // supported, since their width depends on surrounding context this
+    // parser doesn't have). Decimal literals can't contain `x`/`z` digits,
+    // per the LRM. `'d` values are limited to what fits in a `u128`.
+    pub fn from_literal(s: &str) -> Result<SvTypeIntegral, SvLiteralError> {
+        let quote = s.find('\'').ok_or_else(|| SvLiteralError::Syntax(s.to_string()))?;
+        let (size_str, rest) = (&s[..quote], &s[quote + 1..]);
+        let size: u64 = size_str.parse().map_err(|_| SvLiteralError::Syntax(s.to_string()))?;
+        if size == 0 {
+            return Err(SvLiteralError::Syntax(s.to_string()));
+        }
+
+        let mut chars = rest.chars();
+        let mut c = chars.next().ok_or_else(|| SvLiteralError::Syntax(s.to_string()))?;
+
+        let signed = c == 's' || c == 'S';
+        if signed {
+            c = chars.next().ok_or_else(|| SvLiteralError::Syntax(s.to_string()))?;
+        }
+
+        let digits: String = chars.filter(|&d| d != '_').collect();
+        if digits.is_empty() {
+            return Err(SvLiteralError::Syntax(s.to_string()));
+        }
+
+        let bits = match c {
+            'b' | 'B' => digits_to_bits(&digits, bin_digit_bits)?,
+            'o' | 'O' => digits_to_bits(&digits, oct_digit_bits)?,
+            'h' | 'H' => digits_to_bits(&digits, hex_digit_bits)?,
+            'd' | 'D' => decimal_to_bits(&digits, size)?,
+            other => return Err(SvLiteralError::UnknownBase(other)),
+        };
+        let bits = resize_bits(bits, size);
+
+        let fourstate = bits.iter().any(|b| matches!(b, SvBit::X | SvBit::Z));
+        Ok(SvTypeIntegral {
+            origin: None,
+            identifier: None,
+            fourstate,
+            sized: true,
+            signed,
+            packed: (size != 1).then(|| vec![(size - 1, 0)]),
+            unpacked: None,
+            value: Some(bits_to_value(&bits, fourstate)),
+        })
+    }
+
+    // Render back to a sized, binary-based literal. All-X or all-Z values
+    // collapse to a single binary `x`/`z` digit (e.g. `8'bx`), which
+    // `from_literal` extends back to the full width.
+    //
+    // Only the packed value itself is rendered: a literal is a single
+    // packed value, so if `unpacked` holds more than one element (as it
+    // never does for a type built by `from_literal`), only the
+    // first-declared element (`unpacked` index 0) is rendered, reusing the
+    // same word addressing as `LayoutCalculator::layout`.
+    pub fn to_literal(&self) -> String {
+        let size = self.packed_bits();
+        let layout = LayoutCalculator::layout(self, usize::BITS as u64);
+        let value = self.value.as_deref().unwrap_or(&[]);
+
+        let bits: Vec<SvBit> = (0..size)
+            .map(|i| {
+                let loc = layout.as_ref().and_then(|l| l.bit_location(0, i));
+                let v = loc.is_some_and(|l| value.get(l.word_index).copied().unwrap_or(0) >> l.bit_offset & 1 != 0);
+                let m = loc.is_some_and(|l| {
+                    l.mask_word_index
+                        .is_some_and(|w| value.get(w).copied().unwrap_or(0) >> l.bit_offset & 1 != 0)
+                });
+                match (v, m) {
+                    (false, false) => SvBit::Zero,
+                    (true, false) => SvBit::One,
+                    (false, true) => SvBit::X,
+                    (true, true) => SvBit::Z,
+                }
+            })
+            .collect();
+
+        let base = if self.signed { "sb" } else { "b" };
+        if size > 0 && bits.iter().all(|&b| b == SvBit::X) {
+            return format!("{}'{}x", size, base);
+        }
+        if size > 0 && bits.iter().all(|&b| b == SvBit::Z) {
+            return format!("{}'{}z", size, base);
+        }
+
+        let digits: String = bits
+            .iter()
+            .rev()
+            .map(|b| match b {
+                SvBit::Zero => '0',
+                SvBit::One => '1',
+                SvBit::X => 'x',
+                SvBit::Z => 'z',
+            })
+            .collect();
+        format!("{}'{}{}", size, base, digits)
+    }
+}
+
+// Extend/truncate a LSB-first bit vector to exactly `size` bits, per the
+// LRM's unsized-digit rule: pad with the MSB's state (0-extend for a `0`
+// or `1` MSB, x/z-extend for an `x`/`z` MSB).
+fn resize_bits(mut bits: Vec<SvBit>, size: u64) -> Vec<SvBit> {
+    let size = size as usize;
+    if bits.len() > size {
+        bits.truncate(size);
+    } else if bits.len() < size {
+        let extend = match bits.last() {
+            Some(SvBit::One) | Some(SvBit::Zero) | None => SvBit::Zero,
+            Some(&other) => other,
+        };
+        bits.resize(size, extend);
+    }
+    bits
+}
+
+// Parse a literal's digit string (MSB digit first, as written) into a
+// LSB-first bit vector, expanding each digit via `digit_bits`.
+fn digits_to_bits(
+    digits: &str,
+    digit_bits: impl Fn(char) -> Result<Vec<SvBit>, SvLiteralError>,
+) -> Result<Vec<SvBit>, SvLiteralError> {
+    let mut bits = Vec::new();
+    for c in digits.chars().rev() {
+        bits.extend(digit_bits(c)?);
+    }
+    Ok(bits)
+}
+
+fn bin_digit_bits(c: char) -> Result<Vec<SvBit>, SvLiteralError> {
+    Ok(vec![match c {
+        '0' => SvBit::Zero,
+        '1' => SvBit::One,
+        'x' | 'X' => SvBit::X,
+        'z' | 'Z' | '?' => SvBit::Z,
+        other => return Err(SvLiteralError::InvalidDigit(other)),
+    }])
+}
+
+fn oct_digit_bits(c: char) -> Result<Vec<SvBit>, SvLiteralError> {
+    match c {
+        'x' | 'X' => Ok(vec![SvBit::X; 3]),
+        'z' | 'Z' | '?' => Ok(vec![SvBit::Z; 3]),
+        '0'..='7' => {
+            let n = c.to_digit(8).unwrap();
+            Ok((0..3).map(|i| if (n >> i) & 1 != 0 { SvBit::One } else { SvBit::Zero }).collect())
+        }
+        other => Err(SvLiteralError::InvalidDigit(other)),
+    }
+}
+
+fn hex_digit_bits(c: char) -> Result<Vec<SvBit>, SvLiteralError> {
+    match c {
+        'x' | 'X' => Ok(vec![SvBit::X; 4]),
+        'z' | 'Z' | '?' => Ok(vec![SvBit::Z; 4]),
+        _ => {
+            let n = c.to_digit(16).ok_or(SvLiteralError::InvalidDigit(c))?;
+            Ok((0..4).map(|i| if (n >> i) & 1 != 0 { SvBit::One } else { SvBit::Zero }).collect())
+        }
+    }
+}
+
+fn decimal_to_bits(digits: &str, size: u64) -> Result<Vec<SvBit>, SvLiteralError> {
+    if digits.chars().any(|c| matches!(c, 'x' | 'X' | 'z' | 'Z' | '?')) {
+        return Err(SvLiteralError::DecimalWithXZ);
+    }
+    if let Some(c) = digits.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(SvLiteralError::InvalidDigit(c));
+    }
+    let value: u128 = digits.parse().map_err(|_| SvLiteralError::ValueTooLarge)?;
+    Ok((0..size).map(|i| if i < 128 && (value >> i) & 1 != 0 { SvBit::One } else { SvBit::Zero }).collect())
+}
+
+// Pack a LSB-first bit vector into `SvTypeIntegral::value`'s encoding: one
+// `usize` per `usize::BITS` bits, doubled for fourstate with the mask word
+// immediately after its value word.
+fn bits_to_value(bits: &[SvBit], fourstate: bool) -> Vec<usize> {
+    let word_bits = usize::BITS as u64;
+    let words = (bits.len() as u64).div_ceil(word_bits).max(1) as usize;
+    let mut data = vec![0usize; words * if fourstate { 2 } else { 1 }];
+
+    for (i, bit) in bits.iter().enumerate() {
+        let word = i / word_bits as usize;
+        let shift = i % word_bits as usize;
+        let (v, m) = match bit {
+            SvBit::Zero => (false, false),
+            SvBit::One => (true, false),
+            SvBit::X => (false, true),
+            SvBit::Z => (true, true),
+        };
+        let stride = if fourstate { 2 } else { 1 };
+        if v {
+            data[word * stride] |= 1 << shift;
+        }
+        if fourstate && m {
+            data[word * stride + 1] |= 1 << shift;
+        }
+    }
+    data
+}
+
 // 6.12 Real, shortreal, and realtime data types (page 105)
 // The `real` data type is the same as a C `double`.
 // The `shortreal` data type is the same as a C `float`.
@@ -294,7 +688,10 @@ pub enum SvType {
     String(Box<SvTypeString>),
     Event(Box<SvTypeEvent>),
     Typedef(Box<SvTypeTypedef>),
-    Enum(Box<SvTypeTypedef>),
+    Enum(Box<SvTypeEnum>),
+    Struct(Box<SvTypeStruct>),
+    Union(Box<SvTypeUnion>),
+    Array(Box<SvTypeArray>),
 }
 
 // 6.22 Type compatibility (page 128)
@@ -303,7 +700,7 @@ pub enum SvType {
 // There are five levels of type compatibility, formally defined here:
 // matching, equivalent, assignment compatible, cast compatible, and
 // nonequivalent.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SvTypesCompatibility {
     Matching,
     Equivalent,
@@ -312,20 +709,1340 @@ pub enum SvTypesCompatibility {
     NonEquivalent,
 }
 
-// TODO: 7 Aggregate data types
-//   TODO: 7.2 Structures
-//   TODO: 7.3 Unions
-//   TODO: 7.4 Packed and unpacked arrays
-//   TODO: 7.5 Dynamic arrays
-//   TODO: 7.8 Associative arrays
-//   TODO: 7.10 Queues
+// Strip `Typedef` layers, resolving down to the underlying structural type.
+// `Enum`/`Class`/other nominal types are left as-is: they're only
+// transparent when wrapped in a `Typedef`, not in themselves.
+fn resolve_typedef(ty: &SvType) -> &SvType {
+    match ty {
+        SvType::Typedef(td) => resolve_typedef(&td.base_type),
+        _ => ty,
+    }
+}
+
+// An anonymous enum/class is equivalent only to itself (the same
+// declaration, reached via possibly-different typedefs); a named one is
+// equivalent to any other reference to the same name.
+fn enum_same(a: &SvTypeEnum, b: &SvTypeEnum) -> bool {
+    match (&a.identifier, &b.identifier) {
+        (Some(ia), Some(ib)) => ia == ib,
+        _ => std::ptr::eq(a, b),
+    }
+}
+fn class_same(a: &SvTypeClass, b: &SvTypeClass) -> bool {
+    match (&a.identifier, &b.identifier) {
+        (Some(ia), Some(ib)) => ia == ib,
+        _ => std::ptr::eq(a, b),
+    }
+}
+
+// `real`/`realtime` are synonymous (see `SvRealType`); `shortreal` is a
+// distinct kind. `None` means the kind isn't known from this value alone.
+fn real_kind(value: &Option<SvRealType>) -> Option<u8> {
+    match value {
+        Some(SvRealType::Real(_)) | Some(SvRealType::Realtime(_)) => Some(0),
+        Some(SvRealType::Shortreal(_)) => Some(1),
+        None => None,
+    }
+}
+
+fn real_compatibility(a: &SvTypeReal, b: &SvTypeReal) -> SvTypesCompatibility {
+    match (real_kind(&a.value), real_kind(&b.value)) {
+        (Some(ka), Some(kb)) if ka == kb => SvTypesCompatibility::Equivalent,
+        _ => SvTypesCompatibility::AssignmentCompatible,
+    }
+}
+
+fn integral_compatibility(a: &SvTypeIntegral, b: &SvTypeIntegral) -> SvTypesCompatibility {
+    // Matching: the same built-in type, i.e. identical fourstate/signed and
+    // identical packed/unpacked dimension lists.
+    if a.fourstate == b.fourstate && a.signed == b.signed && a.packed == b.packed && a.unpacked == b.unpacked {
+        return SvTypesCompatibility::Matching;
+    }
+
+    // Equivalent: same total packed/unpacked shape, possibly written with
+    // different bounds (e.g. `[3:0]` vs `[0:3]` vs two nested `[1:0]`).
+    if a.fourstate == b.fourstate
+        && a.signed == b.signed
+        && dims_bits(&a.packed) == dims_bits(&b.packed)
+        && dims_bits(&a.unpacked) == dims_bits(&b.unpacked)
+    {
+        return SvTypesCompatibility::Equivalent;
+    }
+
+    // Otherwise, any two integral types are inter-assignable with implicit
+    // width/sign conversion.
+    SvTypesCompatibility::AssignmentCompatible
+}
+
+// Classify the type compatibility of `b` being used where `a` is expected,
+// e.g. in the assignment `a = b;`, per LRM 6.22.
+pub fn compatibility(a: &SvType, b: &SvType) -> SvTypesCompatibility {
+    use SvType::*;
+    use SvTypesCompatibility::*;
+
+    let a = resolve_typedef(a);
+    let b = resolve_typedef(b);
+
+    match (a, b) {
+        (Integral(a), Integral(b)) => integral_compatibility(a, b),
+        (Real(a), Real(b)) => real_compatibility(a, b),
+
+        // Per 6.22.2, integral and real types are assignment compatible in
+        // either direction (the integral<->real conversion truncates or
+        // widens as needed, but doesn't require an explicit cast).
+        (Real(_), Integral(_)) => AssignmentCompatible,
+        (Integral(_), Real(_)) => AssignmentCompatible,
+
+        // An enum variable may always be assigned to an integral variable,
+        // but the reverse needs an explicit cast.
+        (Integral(_), Enum(_)) => AssignmentCompatible,
+        (Enum(_), Integral(_)) => CastCompatible,
+
+        (Enum(a), Enum(b)) => {
+            if enum_same(a, b) {
+                Matching
+            } else {
+                NonEquivalent
+            }
+        }
+        (Class(a), Class(b)) => {
+            if class_same(a, b) {
+                Matching
+            } else {
+                NonEquivalent
+            }
+        }
+
+        (Void(_), Void(_)) => Matching,
+        (Chandle(_), Chandle(_)) => Matching,
+        (Event(_), Event(_)) => Matching,
+        (String(_), String(_)) => Matching,
+
+        _ => NonEquivalent,
+    }
+}
+
+// 7 Aggregate data types
+//
+// An error constructing a packed struct/union/array: packed aggregates may
+// only contain members with a computable `$bits` (integral or other packed
+// aggregate types), and a packed union additionally requires every member
+// to have the same `$bits`.
+#[derive(Debug, Clone)]
+pub enum SvAggregateError {
+    PackedMemberNotSized(String),
+    UnionWidthMismatch {
+        identifier: String,
+        expected_bits: u64,
+        found_bits: u64,
+    },
+}
+
+// A single named member of a struct/union.
+#[derive(Debug, Clone)]
+pub struct SvAggregateMember {
+    pub identifier: String,
+    pub member_type: SvType,
+}
+
+// `$bits` of any `SvType`, recursing through nested aggregates. `None` if
+// the type has no computable size (e.g. `string`, or an unsized integral).
+fn sv_type_bits(ty: &SvType) -> Option<u64> {
+    match ty {
+        SvType::Integral(i) => i.bits(),
+        SvType::Struct(s) => s.bits(),
+        SvType::Union(u) => u.bits(),
+        SvType::Array(a) => a.bits(),
+        SvType::Typedef(t) => sv_type_bits(&t.base_type),
+        SvType::Enum(e) => e.base_type.bits(),
+        _ => None,
+    }
+}
+
+// Whether any bit of `ty` is 4-state. Per 7.2.1, a packed struct/union with
+// any 4-state member is itself entirely 4-state.
+fn sv_type_fourstate(ty: &SvType) -> bool {
+    match ty {
+        SvType::Integral(i) => i.fourstate,
+        SvType::Struct(s) => s.members.iter().any(|m| sv_type_fourstate(&m.member_type)),
+        SvType::Union(u) => u.members.iter().any(|m| sv_type_fourstate(&m.member_type)),
+        SvType::Array(a) => sv_type_fourstate(&a.element_type),
+        SvType::Typedef(t) => sv_type_fourstate(&t.base_type),
+        SvType::Enum(e) => e.base_type.fourstate,
+        _ => false,
+    }
+}
+
+// Whether `ty` is legal inside a packed aggregate: integral, or itself a
+// packed aggregate. An *unpacked* struct/union/array has a computable
+// `$bits` but isn't addressable as a flattened packed value, so it may not
+// appear as a member/element of a packed aggregate.
+fn is_integral_or_packed(ty: &SvType) -> bool {
+    match ty {
+        SvType::Integral(_) | SvType::Enum(_) => true,
+        SvType::Struct(s) => s.packed,
+        SvType::Union(u) => u.packed,
+        SvType::Array(a) => a.packed,
+        SvType::Typedef(t) => is_integral_or_packed(&t.base_type),
+        _ => false,
+    }
+}
+
+fn validate_packed_members(members: &[SvAggregateMember]) -> Result<(), SvAggregateError> {
+    for member in members {
+        if !is_integral_or_packed(&member.member_type) || sv_type_bits(&member.member_type).is_none() {
+            return Err(SvAggregateError::PackedMemberNotSized(member.identifier.clone()));
+        }
+    }
+    Ok(())
+}
+
+// 7.2 Structures (page 123)
+// A structure represents a collection of data types that can be of the same
+// or different types, combined together.
+// Structures are generally used to represent a collection of related
+// variables that can be conveniently treated as a unit, rather than as
+// separate variables.
+// Members are declared in `packed` layout (flattened into a single packed
+// value, most-significant member first) or `unpacked` layout (each member
+// usize-aligned and separately indexable), per `SvTypeIntegral::value`.
+#[derive(Debug, Clone)]
+pub struct SvTypeStruct {
+    pub origin: Option<SourceTextOrigin>,
+
+    // None -> anonymous, Some -> named
+    pub identifier: Option<String>,
+
+    pub packed: bool,
+
+    // Most-significant member first, matching declaration order.
+    pub members: Vec<SvAggregateMember>,
+}
+
+impl SvTypeStruct {
+    pub fn new(
+        origin: Option<SourceTextOrigin>,
+        identifier: Option<String>,
+        packed: bool,
+        members: Vec<SvAggregateMember>,
+    ) -> Result<SvTypeStruct, SvAggregateError> {
+        if packed {
+            validate_packed_members(&members)?;
+        }
+        Ok(SvTypeStruct { origin, identifier, packed, members })
+    }
+
+    // Total `$bits`, recursing through nested aggregates. `None` if any
+    // member has no computable size.
+    pub fn bits(&self) -> Option<u64> {
+        self.members.iter().map(|m| sv_type_bits(&m.member_type)).sum()
+    }
+
+    // The `(word_index, bit_offset)` location of `member_index`'s LSB
+    // within the flattened packed representation. `None` if this struct
+    // isn't `packed`, the index is out of range, or the layout isn't
+    // computable.
+    pub fn member_bit_location(&self, usize_bits: u64, member_index: usize) -> Option<SvBitLocation> {
+        let layout = LayoutCalculator::layout(self, usize_bits)?;
+        self.members.get(member_index)?;
+
+        // Members are packed most-significant-first, so this member's LSB
+        // sits as many bits above 0 as the combined width of every member
+        // declared after it.
+        let offset: u64 = self.members[member_index + 1..]
+            .iter()
+            .map(|m| sv_type_bits(&m.member_type))
+            .sum::<Option<u64>>()?;
+        layout.bit_location(0, offset)
+    }
+}
+
+impl LayoutCalculator for SvTypeStruct {
+    fn layout(&self, usize_bits: u64) -> Option<SvLayout> {
+        if !self.packed {
+            return None;
+        }
+        Some(SvLayout::from_bits(self.bits()?, sv_type_fourstate_members(&self.members), usize_bits))
+    }
+}
+
+// 7.3 Unions (page 127)
+// A union represents a single piece of storage that can be accessed using
+// one of the named member types that it contains, all members overlaid at
+// the same base offset.
+// A packed union additionally requires that all of its members have the
+// same `$bits`, so that there is a single well-defined bit-packing for the
+// overlaid storage.
+#[derive(Debug, Clone)]
+pub struct SvTypeUnion {
+    pub origin: Option<SourceTextOrigin>,
+
+    // None -> anonymous, Some -> named
+    pub identifier: Option<String>,
+
+    pub packed: bool,
+
+    pub members: Vec<SvAggregateMember>,
+}
+
+impl SvTypeUnion {
+    pub fn new(
+        origin: Option<SourceTextOrigin>,
+        identifier: Option<String>,
+        packed: bool,
+        members: Vec<SvAggregateMember>,
+    ) -> Result<SvTypeUnion, SvAggregateError> {
+        if packed {
+            validate_packed_members(&members)?;
+
+            let mut expected_bits = None;
+            for member in &members {
+                // Safe to unwrap: `validate_packed_members` above already
+                // confirmed every member is sized.
+                let bits = sv_type_bits(&member.member_type).unwrap();
+                match expected_bits {
+                    None => expected_bits = Some(bits),
+                    Some(expected) if expected != bits => {
+                        return Err(SvAggregateError::UnionWidthMismatch {
+                            identifier: member.identifier.clone(),
+                            expected_bits: expected,
+                            found_bits: bits,
+                        })
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(SvTypeUnion { origin, identifier, packed, members })
+    }
+
+    // `$bits` of the storage shared by all members, i.e. the widest member.
+    // `None` if no member has a computable size.
+    pub fn bits(&self) -> Option<u64> {
+        self.members.iter().filter_map(|m| sv_type_bits(&m.member_type)).max()
+    }
+
+    // The `(word_index, bit_offset)` location of `member_index`'s LSB.
+    // Every member overlays the same base offset, so this is always bit 0
+    // of the union's flattened value. `None` if this union isn't `packed`
+    // or the index is out of range.
+    pub fn member_bit_location(&self, usize_bits: u64, member_index: usize) -> Option<SvBitLocation> {
+        let layout = LayoutCalculator::layout(self, usize_bits)?;
+        self.members.get(member_index)?;
+        layout.bit_location(0, 0)
+    }
+}
+
+impl LayoutCalculator for SvTypeUnion {
+    fn layout(&self, usize_bits: u64) -> Option<SvLayout> {
+        if !self.packed {
+            return None;
+        }
+        Some(SvLayout::from_bits(self.bits()?, sv_type_fourstate_members(&self.members), usize_bits))
+    }
+}
+
+// 7.4 Packed and unpacked arrays (page 128)
+// An array is a collection of variables, all of the same type, that are
+// accessed using one or more indices.
+// Reuses `SvTypeIntegral::value`'s bit-packing scheme: a `packed` array
+// flattens its elements into a single packed value (first-declared element
+// at the highest offset, mirroring `SvTypeIntegral`'s unpacked dimensions);
+// an unpacked array keeps elements usize-aligned and separately indexable.
+#[derive(Debug, Clone)]
+pub struct SvTypeArray {
+    pub origin: Option<SourceTextOrigin>,
+
+    // None -> anonymous, Some -> named
+    pub identifier: Option<String>,
+
+    pub packed: bool,
+
+    pub dims: Vec<(u64, u64)>,
+
+    pub element_type: Box<SvType>,
+}
+
+impl SvTypeArray {
+    pub fn new(
+        origin: Option<SourceTextOrigin>,
+        identifier: Option<String>,
+        packed: bool,
+        dims: Vec<(u64, u64)>,
+        element_type: SvType,
+    ) -> Result<SvTypeArray, SvAggregateError> {
+        if packed && (!is_integral_or_packed(&element_type) || sv_type_bits(&element_type).is_none()) {
+            return Err(SvAggregateError::PackedMemberNotSized(
+                identifier.clone().unwrap_or_default(),
+            ));
+        }
+        Ok(SvTypeArray { origin, identifier, packed, dims, element_type: Box::new(element_type) })
+    }
+
+    // Total `$bits`, i.e. the element's `$bits` times the element count.
+    // `None` if the element type has no computable size.
+    pub fn bits(&self) -> Option<u64> {
+        Some(sv_type_bits(&self.element_type)? * dims_count(&self.dims))
+    }
+
+    // The `(word_index, bit_offset)` location of `index`'s LSB within the
+    // flattened packed representation. `None` if this array isn't
+    // `packed`, `index` is out of range, or the layout isn't computable.
+    pub fn element_bit_location(&self, usize_bits: u64, index: u64) -> Option<SvBitLocation> {
+        let layout = LayoutCalculator::layout(self, usize_bits)?;
+        let element_bits = sv_type_bits(&self.element_type)?;
+        let count = dims_count(&self.dims);
+        if index >= count {
+            return None;
+        }
+
+        // First-declared element at the highest offset, as for
+        // `SvTypeIntegral`'s unpacked dimensions.
+        let offset = (count - 1 - index) * element_bits;
+        layout.bit_location(0, offset)
+    }
+}
+
+impl LayoutCalculator for SvTypeArray {
+    fn layout(&self, usize_bits: u64) -> Option<SvLayout> {
+        if !self.packed {
+            return None;
+        }
+        Some(SvLayout::from_bits(self.bits()?, sv_type_fourstate(&self.element_type), usize_bits))
+    }
+}
+
+fn sv_type_fourstate_members(members: &[SvAggregateMember]) -> bool {
+    members.iter().any(|m| sv_type_fourstate(&m.member_type))
+}
+
+// TODO: 7.5 Dynamic arrays
+// TODO: 7.8 Associative arrays
+// TODO: 7.10 Queues
 // TODO: 8 Classes
 
+// Visitor/folder traversal over an `SvType` tree.
+//
+// Typedefs point to `base_type`, enums hold members, and structs/unions/
+// arrays nest arbitrarily, so walking or transforming a type graph by hand
+// means re-writing the same recursion at every call site. `SvTypeVisitor`
+// and `SvTypeFolder` give each node a default walk (`super_*`) that
+// `visit_*`/`fold_*` call into, mirroring a stable IR visitor: override
+// `visit_*`/`fold_*` to act on a node, and `super_*` only if what counts as
+// "children" should change.
+
+// A read-only traversal over an `SvType` tree.
+pub trait SvTypeVisitor {
+    fn visit_type(&mut self, ty: &SvType) {
+        self.super_type(ty)
+    }
+    fn visit_integral(&mut self, ty: &SvTypeIntegral) {
+        self.super_integral(ty)
+    }
+    fn visit_real(&mut self, ty: &SvTypeReal) {
+        self.super_real(ty)
+    }
+    fn visit_void(&mut self, ty: &SvTypeVoid) {
+        self.super_void(ty)
+    }
+    fn visit_chandle(&mut self, ty: &SvTypeChandle) {
+        self.super_chandle(ty)
+    }
+    fn visit_class(&mut self, ty: &SvTypeClass) {
+        self.super_class(ty)
+    }
+    fn visit_string(&mut self, ty: &SvTypeString) {
+        self.super_string(ty)
+    }
+    fn visit_event(&mut self, ty: &SvTypeEvent) {
+        self.super_event(ty)
+    }
+    fn visit_typedef(&mut self, ty: &SvTypeTypedef) {
+        self.super_typedef(ty)
+    }
+    fn visit_enum(&mut self, ty: &SvTypeEnum) {
+        self.super_enum(ty)
+    }
+    fn visit_enum_member(&mut self, member: &SvEnumMember) {
+        self.super_enum_member(member)
+    }
+    fn visit_struct(&mut self, ty: &SvTypeStruct) {
+        self.super_struct(ty)
+    }
+    fn visit_union(&mut self, ty: &SvTypeUnion) {
+        self.super_union(ty)
+    }
+    fn visit_array(&mut self, ty: &SvTypeArray) {
+        self.super_array(ty)
+    }
+    fn visit_aggregate_member(&mut self, member: &SvAggregateMember) {
+        self.super_aggregate_member(member)
+    }
+    fn visit_origin(&mut self, _origin: &SourceTextOrigin) {}
+
+    fn super_type(&mut self, ty: &SvType) {
+        match ty {
+            SvType::Integral(t) => self.visit_integral(t),
+            SvType::Real(t) => self.visit_real(t),
+            SvType::Void(t) => self.visit_void(t),
+            SvType::Chandle(t) => self.visit_chandle(t),
+            SvType::Class(t) => self.visit_class(t),
+            SvType::String(t) => self.visit_string(t),
+            SvType::Event(t) => self.visit_event(t),
+            SvType::Typedef(t) => self.visit_typedef(t),
+            SvType::Enum(t) => self.visit_enum(t),
+            SvType::Struct(t) => self.visit_struct(t),
+            SvType::Union(t) => self.visit_union(t),
+            SvType::Array(t) => self.visit_array(t),
+        }
+    }
+    fn super_integral(&mut self, ty: &SvTypeIntegral) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+    }
+    fn super_real(&mut self, ty: &SvTypeReal) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+    }
+    fn super_void(&mut self, ty: &SvTypeVoid) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+    }
+    fn super_chandle(&mut self, ty: &SvTypeChandle) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+    }
+    fn super_class(&mut self, ty: &SvTypeClass) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+    }
+    fn super_string(&mut self, ty: &SvTypeString) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+    }
+    fn super_event(&mut self, ty: &SvTypeEvent) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+    }
+    fn super_typedef(&mut self, ty: &SvTypeTypedef) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+        self.visit_type(&ty.base_type);
+    }
+    fn super_enum(&mut self, ty: &SvTypeEnum) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+        self.visit_integral(&ty.base_type);
+        for member in &ty.members {
+            self.visit_enum_member(member);
+        }
+    }
+    fn super_enum_member(&mut self, member: &SvEnumMember) {
+        if let Some(origin) = &member.origin {
+            self.visit_origin(origin);
+        }
+        self.visit_integral(&member.value);
+    }
+    fn super_struct(&mut self, ty: &SvTypeStruct) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+        for member in &ty.members {
+            self.visit_aggregate_member(member);
+        }
+    }
+    fn super_union(&mut self, ty: &SvTypeUnion) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+        for member in &ty.members {
+            self.visit_aggregate_member(member);
+        }
+    }
+    fn super_array(&mut self, ty: &SvTypeArray) {
+        if let Some(origin) = &ty.origin {
+            self.visit_origin(origin);
+        }
+        self.visit_type(&ty.element_type);
+    }
+    fn super_aggregate_member(&mut self, member: &SvAggregateMember) {
+        self.visit_type(&member.member_type);
+    }
+}
+
+// A tree-rebuilding transformation over an `SvType` tree. The default
+// implementation of every `fold_*` method reconstructs the node unchanged
+// (folding its children); override one to change that node's shape.
+pub trait SvTypeFolder {
+    fn fold_type(&mut self, ty: SvType) -> SvType {
+        self.super_fold_type(ty)
+    }
+    fn fold_integral(&mut self, ty: SvTypeIntegral) -> SvTypeIntegral {
+        ty
+    }
+    fn fold_real(&mut self, ty: SvTypeReal) -> SvTypeReal {
+        ty
+    }
+    fn fold_void(&mut self, ty: SvTypeVoid) -> SvTypeVoid {
+        ty
+    }
+    fn fold_chandle(&mut self, ty: SvTypeChandle) -> SvTypeChandle {
+        ty
+    }
+    fn fold_class(&mut self, ty: SvTypeClass) -> SvTypeClass {
+        ty
+    }
+    fn fold_string(&mut self, ty: SvTypeString) -> SvTypeString {
+        ty
+    }
+    fn fold_event(&mut self, ty: SvTypeEvent) -> SvTypeEvent {
+        ty
+    }
+    fn fold_typedef(&mut self, ty: SvTypeTypedef) -> SvType {
+        self.super_fold_typedef(ty)
+    }
+    fn fold_enum(&mut self, ty: SvTypeEnum) -> SvTypeEnum {
+        self.super_fold_enum(ty)
+    }
+    fn fold_enum_member(&mut self, member: SvEnumMember) -> SvEnumMember {
+        self.super_fold_enum_member(member)
+    }
+    fn fold_struct(&mut self, ty: SvTypeStruct) -> SvTypeStruct {
+        self.super_fold_struct(ty)
+    }
+    fn fold_union(&mut self, ty: SvTypeUnion) -> SvTypeUnion {
+        self.super_fold_union(ty)
+    }
+    fn fold_array(&mut self, ty: SvTypeArray) -> SvTypeArray {
+        self.super_fold_array(ty)
+    }
+    fn fold_aggregate_member(&mut self, member: SvAggregateMember) -> SvAggregateMember {
+        self.super_fold_aggregate_member(member)
+    }
+
+    fn super_fold_type(&mut self, ty: SvType) -> SvType {
+        match ty {
+            SvType::Integral(t) => SvType::Integral(Box::new(self.fold_integral(*t))),
+            SvType::Real(t) => SvType::Real(Box::new(self.fold_real(*t))),
+            SvType::Void(t) => SvType::Void(Box::new(self.fold_void(*t))),
+            SvType::Chandle(t) => SvType::Chandle(Box::new(self.fold_chandle(*t))),
+            SvType::Class(t) => SvType::Class(Box::new(self.fold_class(*t))),
+            SvType::String(t) => SvType::String(Box::new(self.fold_string(*t))),
+            SvType::Event(t) => SvType::Event(Box::new(self.fold_event(*t))),
+            SvType::Typedef(t) => self.fold_typedef(*t),
+            SvType::Enum(t) => SvType::Enum(Box::new(self.fold_enum(*t))),
+            SvType::Struct(t) => SvType::Struct(Box::new(self.fold_struct(*t))),
+            SvType::Union(t) => SvType::Union(Box::new(self.fold_union(*t))),
+            SvType::Array(t) => SvType::Array(Box::new(self.fold_array(*t))),
+        }
+    }
+    fn super_fold_typedef(&mut self, ty: SvTypeTypedef) -> SvType {
+        SvType::Typedef(Box::new(SvTypeTypedef {
+            origin: ty.origin,
+            identifier: ty.identifier,
+            base_type: Box::new(self.fold_type(*ty.base_type)),
+        }))
+    }
+    fn super_fold_enum(&mut self, ty: SvTypeEnum) -> SvTypeEnum {
+        SvTypeEnum {
+            origin: ty.origin,
+            identifier: ty.identifier,
+            base_type: Box::new(self.fold_integral(*ty.base_type)),
+            members: ty.members.into_iter().map(|m| self.fold_enum_member(m)).collect(),
+        }
+    }
+    fn super_fold_enum_member(&mut self, member: SvEnumMember) -> SvEnumMember {
+        SvEnumMember {
+            origin: member.origin,
+            identifier: member.identifier,
+            value: Box::new(self.fold_integral(*member.value)),
+        }
+    }
+    fn super_fold_struct(&mut self, ty: SvTypeStruct) -> SvTypeStruct {
+        SvTypeStruct {
+            origin: ty.origin,
+            identifier: ty.identifier,
+            packed: ty.packed,
+            members: ty.members.into_iter().map(|m| self.fold_aggregate_member(m)).collect(),
+        }
+    }
+    fn super_fold_union(&mut self, ty: SvTypeUnion) -> SvTypeUnion {
+        SvTypeUnion {
+            origin: ty.origin,
+            identifier: ty.identifier,
+            packed: ty.packed,
+            members: ty.members.into_iter().map(|m| self.fold_aggregate_member(m)).collect(),
+        }
+    }
+    fn super_fold_array(&mut self, ty: SvTypeArray) -> SvTypeArray {
+        SvTypeArray {
+            origin: ty.origin,
+            identifier: ty.identifier,
+            packed: ty.packed,
+            dims: ty.dims,
+            element_type: Box::new(self.fold_type(*ty.element_type)),
+        }
+    }
+    fn super_fold_aggregate_member(&mut self, member: SvAggregateMember) -> SvAggregateMember {
+        SvAggregateMember {
+            identifier: member.identifier,
+            member_type: self.fold_type(member.member_type),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
         let result = 2 + 2;
         assert_eq!(result, 4);
     }
+
+    // Minimal `SvTypeIntegral` with only the fields the layout calculator
+    // cares about set explicitly.
+    fn integral(
+        fourstate: bool,
+        signed: bool,
+        packed: Option<Vec<(u64, u64)>>,
+        unpacked: Option<Vec<(u64, u64)>>,
+    ) -> SvTypeIntegral {
+        SvTypeIntegral {
+            origin: None,
+            identifier: None,
+            fourstate,
+            sized: true,
+            signed,
+            packed,
+            unpacked,
+            value: None,
+        }
+    }
+
+    // Example 1/2: twostate 1b scalar.
+    #[test]
+    fn layout_example_1_2() {
+        let layout = integral(false, false, None, None).layout(64).unwrap();
+        assert_eq!(layout.bits, 1);
+        assert_eq!(layout.words(), 1);
+        assert_eq!(
+            layout.bit_location(0, 0).unwrap(),
+            SvBitLocation { word_index: 0, bit_offset: 0, mask_word_index: None }
+        );
+    }
+
+    // Example 3-6: fourstate 1b scalar.
+    #[test]
+    fn layout_example_3_to_6() {
+        let layout = integral(true, false, None, None).layout(64).unwrap();
+        assert_eq!(layout.bits, 1);
+        assert_eq!(layout.words(), 2);
+        assert_eq!(
+            layout.bit_location(0, 0).unwrap(),
+            SvBitLocation { word_index: 0, bit_offset: 0, mask_word_index: Some(1) }
+        );
+    }
+
+    // Example 7: twostate packed 5b.
+    #[test]
+    fn layout_example_7() {
+        let layout = integral(false, false, Some(vec![(4, 0)]), None).layout(64).unwrap();
+        assert_eq!(layout.bits, 5);
+        assert_eq!(layout.words(), 1);
+    }
+
+    // Example 8: twostate packed 45b (usize=32).
+    #[test]
+    fn layout_example_8() {
+        let layout = integral(false, false, Some(vec![(44, 0)]), None).layout(32).unwrap();
+        assert_eq!(layout.bits, 45);
+        assert_eq!(layout.words(), 2);
+        assert_eq!(layout.bit_location(0, 0).unwrap().word_index, 0);
+        assert_eq!(layout.bit_location(0, 32).unwrap().word_index, 1);
+    }
+
+    // Example 9: fourstate packed 5b.
+    #[test]
+    fn layout_example_9() {
+        let layout = integral(true, false, Some(vec![(4, 0)]), None).layout(64).unwrap();
+        assert_eq!(layout.bits, 5);
+        assert_eq!(layout.words(), 2);
+        let loc = layout.bit_location(0, 1).unwrap();
+        assert_eq!((loc.word_index, loc.mask_word_index), (0, Some(1)));
+    }
+
+    // Example 10: fourstate packed 45b (usize=32).
+    #[test]
+    fn layout_example_10() {
+        let layout = integral(true, false, Some(vec![(44, 0)]), None).layout(32).unwrap();
+        assert_eq!(layout.bits, 45);
+        assert_eq!(layout.words(), 4);
+        assert_eq!(layout.bit_location(0, 40).unwrap().word_index, 2);
+        assert_eq!(layout.bit_location(0, 40).unwrap().mask_word_index, Some(3));
+    }
+
+    // Example 11: twostate packed 1b, unpacked x5.
+    #[test]
+    fn layout_example_11() {
+        let layout = integral(false, false, None, Some(vec![(4, 0)])).layout(64).unwrap();
+        assert_eq!(layout.bits, 5);
+        assert_eq!(layout.words(), 5);
+        // The first-declared element (index 0) lands in the last word.
+        assert_eq!(layout.bit_location(0, 0).unwrap().word_index, 4);
+        assert_eq!(layout.bit_location(4, 0).unwrap().word_index, 0);
+    }
+
+    // Example 12: fourstate packed 1b, unpacked x5.
+    #[test]
+    fn layout_example_12() {
+        let layout = integral(true, false, None, Some(vec![(4, 0)])).layout(64).unwrap();
+        assert_eq!(layout.bits, 5);
+        assert_eq!(layout.words(), 10);
+        assert_eq!(layout.bit_location(0, 0).unwrap().word_index, 8);
+        assert_eq!(layout.bit_location(4, 0).unwrap().word_index, 0);
+    }
+
+    // Example 13: twostate packed 45b, unpacked x5 (usize=32).
+    #[test]
+    fn layout_example_13() {
+        let layout = integral(false, false, Some(vec![(44, 0)]), Some(vec![(4, 0)]))
+            .layout(32)
+            .unwrap();
+        assert_eq!(layout.bits, 225);
+        assert_eq!(layout.words(), 10);
+        assert_eq!(layout.bit_location(0, 0).unwrap().word_index, 8);
+        assert_eq!(layout.bit_location(4, 0).unwrap().word_index, 0);
+    }
+
+    // Example 14: fourstate packed 45b, unpacked x5 (usize=32).
+    #[test]
+    fn layout_example_14() {
+        let layout = integral(true, false, Some(vec![(44, 0)]), Some(vec![(4, 0)]))
+            .layout(32)
+            .unwrap();
+        assert_eq!(layout.bits, 225);
+        assert_eq!(layout.words(), 20);
+        assert_eq!(layout.bit_location(0, 0).unwrap().word_index, 16);
+        assert_eq!(layout.bit_location(0, 0).unwrap().mask_word_index, Some(17));
+        assert_eq!(layout.bit_location(4, 0).unwrap().word_index, 0);
+    }
+
+    // Edge case: `sized=false` has no computable `$bits`.
+    #[test]
+    fn layout_unsized_is_none() {
+        let mut ty = integral(false, false, None, None);
+        ty.sized = false;
+        assert!(ty.layout(64).is_none());
+    }
+
+    // Edge case: width exactly a multiple of `usize`.
+    #[test]
+    fn layout_exact_usize_multiple() {
+        let layout = integral(false, false, Some(vec![(63, 0)]), None).layout(64).unwrap();
+        assert_eq!(layout.bits, 64);
+        assert_eq!(layout.words(), 1);
+
+        let layout = integral(false, false, Some(vec![(127, 0)]), None).layout(64).unwrap();
+        assert_eq!(layout.words(), 2);
+    }
+
+    fn ty_integral(
+        fourstate: bool,
+        signed: bool,
+        packed: Option<Vec<(u64, u64)>>,
+        unpacked: Option<Vec<(u64, u64)>>,
+    ) -> SvType {
+        SvType::Integral(Box::new(integral(fourstate, signed, packed, unpacked)))
+    }
+
+    fn ty_real(value: Option<SvRealType>) -> SvType {
+        SvType::Real(Box::new(SvTypeReal { origin: None, identifier: None, value }))
+    }
+
+    fn ty_void() -> SvType {
+        SvType::Void(Box::new(SvTypeVoid { origin: None }))
+    }
+
+    fn ty_chandle() -> SvType {
+        SvType::Chandle(Box::new(SvTypeChandle { origin: None, value: 0 }))
+    }
+
+    fn ty_string() -> SvType {
+        SvType::String(Box::new(SvTypeString { origin: None, value: None }))
+    }
+
+    fn ty_event() -> SvType {
+        SvType::Event(Box::new(SvTypeEvent {
+            origin: None,
+            identifier: None,
+            sync_object: SvSynchronisationObject { queue: vec![] },
+        }))
+    }
+
+    fn ty_enum(identifier: Option<&str>) -> SvType {
+        SvType::Enum(Box::new(SvTypeEnum {
+            origin: None,
+            identifier: identifier.map(String::from),
+            base_type: Box::new(integral(false, false, None, None)),
+            members: vec![],
+        }))
+    }
+
+    fn ty_typedef(identifier: Option<&str>, base_type: SvType) -> SvType {
+        SvType::Typedef(Box::new(SvTypeTypedef {
+            origin: None,
+            identifier: identifier.map(String::from),
+            base_type: Box::new(base_type),
+        }))
+    }
+
+    #[test]
+    fn compat_integral_matching() {
+        let a = ty_integral(true, false, Some(vec![(3, 0)]), None);
+        let b = ty_integral(true, false, Some(vec![(3, 0)]), None);
+        assert_eq!(compatibility(&a, &b), SvTypesCompatibility::Matching);
+    }
+
+    #[test]
+    fn compat_integral_equivalent_different_bounds() {
+        let a = ty_integral(false, false, Some(vec![(3, 0)]), None);
+        let b = ty_integral(false, false, Some(vec![(0, 3)]), None);
+        assert_eq!(compatibility(&a, &b), SvTypesCompatibility::Equivalent);
+
+        let c = ty_integral(false, false, Some(vec![(1, 0), (1, 0)]), None);
+        assert_eq!(compatibility(&a, &c), SvTypesCompatibility::Equivalent);
+    }
+
+    #[test]
+    fn compat_integral_assignment_compatible_differing_width() {
+        let a = ty_integral(false, false, Some(vec![(3, 0)]), None);
+        let b = ty_integral(false, false, Some(vec![(7, 0)]), None);
+        assert_eq!(compatibility(&a, &b), SvTypesCompatibility::AssignmentCompatible);
+
+        let signed = ty_integral(false, true, Some(vec![(3, 0)]), None);
+        assert_eq!(compatibility(&a, &signed), SvTypesCompatibility::AssignmentCompatible);
+    }
+
+    #[test]
+    fn compat_typedef_resolves_to_definition() {
+        let base = ty_integral(false, false, Some(vec![(3, 0)]), None);
+        let named = ty_typedef(Some("nibble_t"), base.clone());
+        assert_eq!(compatibility(&named, &base), SvTypesCompatibility::Matching);
+        assert_eq!(compatibility(&base, &named), SvTypesCompatibility::Matching);
+    }
+
+    #[test]
+    fn compat_real_and_integral() {
+        let real = ty_real(Some(SvRealType::Real(None)));
+        let shortreal = ty_real(Some(SvRealType::Shortreal(None)));
+        let realtime = ty_real(Some(SvRealType::Realtime(None)));
+        let int = ty_integral(false, true, Some(vec![(31, 0)]), None);
+
+        assert_eq!(compatibility(&real, &realtime), SvTypesCompatibility::Equivalent);
+        assert_eq!(compatibility(&real, &shortreal), SvTypesCompatibility::AssignmentCompatible);
+        assert_eq!(compatibility(&real, &int), SvTypesCompatibility::AssignmentCompatible);
+        assert_eq!(compatibility(&int, &real), SvTypesCompatibility::AssignmentCompatible);
+    }
+
+    #[test]
+    fn compat_enum_and_integral() {
+        let e = ty_enum(Some("color_t"));
+        let int = ty_integral(false, false, None, None);
+
+        assert_eq!(compatibility(&int, &e), SvTypesCompatibility::AssignmentCompatible);
+        assert_eq!(compatibility(&e, &int), SvTypesCompatibility::CastCompatible);
+    }
+
+    #[test]
+    fn compat_enum_identity() {
+        let a = ty_enum(Some("color_t"));
+        let b = ty_enum(Some("color_t"));
+        let c = ty_enum(Some("other_t"));
+        assert_eq!(compatibility(&a, &b), SvTypesCompatibility::Matching);
+        assert_eq!(compatibility(&a, &c), SvTypesCompatibility::NonEquivalent);
+
+        let anon_a = ty_enum(None);
+        let anon_b = ty_enum(None);
+        assert_eq!(compatibility(&anon_a, &anon_b), SvTypesCompatibility::NonEquivalent);
+        assert_eq!(compatibility(&anon_a, &anon_a), SvTypesCompatibility::Matching);
+    }
+
+    #[test]
+    fn compat_singleton_types_only_match_themselves() {
+        let singletons: Vec<SvType> = vec![ty_void(), ty_chandle(), ty_string(), ty_event()];
+        let int = ty_integral(false, false, None, None);
+
+        for (i, a) in singletons.iter().enumerate() {
+            for (j, b) in singletons.iter().enumerate() {
+                let expected = if i == j { SvTypesCompatibility::Matching } else { SvTypesCompatibility::NonEquivalent };
+                assert_eq!(compatibility(a, b), expected);
+            }
+            assert_eq!(compatibility(a, &int), SvTypesCompatibility::NonEquivalent);
+            assert_eq!(compatibility(&int, a), SvTypesCompatibility::NonEquivalent);
+        }
+    }
+
+    fn member(identifier: &str, member_type: SvType) -> SvAggregateMember {
+        SvAggregateMember { identifier: identifier.to_string(), member_type }
+    }
+
+    // packed struct { bit [7:0] a; bit b; }
+    #[test]
+    fn struct_packed_bits_and_member_location() {
+        let s = SvTypeStruct::new(
+            None,
+            None,
+            true,
+            vec![
+                member("a", ty_integral(false, false, Some(vec![(7, 0)]), None)),
+                member("b", ty_integral(false, false, None, None)),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(s.bits(), Some(9));
+
+        // `a` is declared first, so it occupies the most-significant bits:
+        // offset 1 (above `b`'s single bit at offset 0).
+        let a_loc = s.member_bit_location(64, 0).unwrap();
+        assert_eq!(a_loc, SvBitLocation { word_index: 0, bit_offset: 1, mask_word_index: None });
+
+        let b_loc = s.member_bit_location(64, 1).unwrap();
+        assert_eq!(b_loc, SvBitLocation { word_index: 0, bit_offset: 0, mask_word_index: None });
+    }
+
+    #[test]
+    fn struct_packed_rejects_unsized_member() {
+        let err = SvTypeStruct::new(None, None, true, vec![member("s", ty_string())]).unwrap_err();
+        assert!(matches!(err, SvAggregateError::PackedMemberNotSized(id) if id == "s"));
+    }
+
+    #[test]
+    fn struct_packed_rejects_unpacked_aggregate_member() {
+        // An unpacked struct has a computable `$bits` but isn't itself a
+        // flattened packed value, so it can't be a packed struct's member.
+        let inner = SvTypeStruct::new(
+            None,
+            None,
+            false,
+            vec![member("x", ty_integral(false, false, Some(vec![(3, 0)]), None))],
+        )
+        .unwrap();
+
+        let err =
+            SvTypeStruct::new(None, None, true, vec![member("inner", SvType::Struct(Box::new(inner)))])
+                .unwrap_err();
+        assert!(matches!(err, SvAggregateError::PackedMemberNotSized(id) if id == "inner"));
+    }
+
+    #[test]
+    fn struct_unpacked_allows_any_member() {
+        let s = SvTypeStruct::new(None, None, false, vec![member("s", ty_string())]).unwrap();
+        assert_eq!(s.bits(), None);
+    }
+
+    #[test]
+    fn union_packed_requires_equal_bits() {
+        let err = SvTypeUnion::new(
+            None,
+            None,
+            true,
+            vec![
+                member("a", ty_integral(false, false, Some(vec![(7, 0)]), None)),
+                member("b", ty_integral(false, false, Some(vec![(3, 0)]), None)),
+            ],
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            SvAggregateError::UnionWidthMismatch { identifier, expected_bits: 8, found_bits: 4 }
+                if identifier == "b"
+        ));
+    }
+
+    #[test]
+    fn union_packed_overlays_members_at_zero() {
+        let u = SvTypeUnion::new(
+            None,
+            None,
+            true,
+            vec![
+                member("a", ty_integral(false, false, Some(vec![(7, 0)]), None)),
+                member("b", ty_integral(false, false, Some(vec![(7, 0)]), None)),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(u.bits(), Some(8));
+        assert_eq!(u.member_bit_location(64, 0), u.member_bit_location(64, 1));
+    }
+
+    #[test]
+    fn array_packed_bits_and_element_location() {
+        let a = SvTypeArray::new(
+            None,
+            None,
+            true,
+            vec![(4, 0)],
+            ty_integral(false, false, Some(vec![(7, 0)]), None),
+        )
+        .unwrap();
+
+        assert_eq!(a.bits(), Some(40));
+
+        // Element 0 is first-declared, so it lands at the highest offset.
+        assert_eq!(a.element_bit_location(64, 0).unwrap().bit_offset, 32);
+        assert_eq!(a.element_bit_location(64, 4).unwrap().bit_offset, 0);
+        assert!(a.element_bit_location(64, 5).is_none());
+    }
+
+    #[test]
+    fn array_packed_rejects_unsized_element() {
+        let err = SvTypeArray::new(None, None, true, vec![(3, 0)], ty_string()).unwrap_err();
+        assert!(matches!(err, SvAggregateError::PackedMemberNotSized(_)));
+    }
+
+    #[test]
+    fn array_packed_rejects_unpacked_aggregate_element() {
+        let inner = SvTypeStruct::new(
+            None,
+            None,
+            false,
+            vec![member("x", ty_integral(false, false, Some(vec![(3, 0)]), None))],
+        )
+        .unwrap();
+
+        let err =
+            SvTypeArray::new(None, None, true, vec![(3, 0)], SvType::Struct(Box::new(inner))).unwrap_err();
+        assert!(matches!(err, SvAggregateError::PackedMemberNotSized(_)));
+    }
+
+    #[test]
+    fn nested_aggregate_bits_recurse() {
+        let inner = SvTypeStruct::new(
+            None,
+            None,
+            true,
+            vec![member("x", ty_integral(false, false, Some(vec![(3, 0)]), None))],
+        )
+        .unwrap();
+
+        let outer = SvTypeStruct::new(
+            None,
+            None,
+            true,
+            vec![
+                member("inner", SvType::Struct(Box::new(inner))),
+                member("y", ty_integral(false, false, Some(vec![(3, 0)]), None)),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(outer.bits(), Some(8));
+    }
+
+    #[test]
+    fn struct_fourstate_promotes_whole_struct() {
+        let s = SvTypeStruct::new(
+            None,
+            None,
+            true,
+            vec![
+                member("a", ty_integral(false, false, Some(vec![(3, 0)]), None)),
+                member("b", ty_integral(true, false, None, None)),
+            ],
+        )
+        .unwrap();
+
+        // Any 4-state member makes the whole packed struct 4-state, so the
+        // total word count doubles.
+        let layout = LayoutCalculator::layout(&s, 64).unwrap();
+        assert_eq!(layout.bits, 5);
+        assert_eq!(layout.words(), 2);
+    }
+
+    #[derive(Default)]
+    struct NodeCounter {
+        count: usize,
+    }
+    impl SvTypeVisitor for NodeCounter {
+        fn visit_type(&mut self, ty: &SvType) {
+            self.count += 1;
+            self.super_type(ty);
+        }
+    }
+
+    #[test]
+    fn visitor_counts_nodes() {
+        // typedef nibble_t = bit [3:0]; struct { nibble_t a; bit [3:0] b; }
+        let leaf = ty_integral(false, false, Some(vec![(3, 0)]), None);
+        let named = ty_typedef(Some("nibble_t"), leaf.clone());
+        let s = SvType::Struct(Box::new(
+            SvTypeStruct::new(None, None, true, vec![member("a", named), member("b", leaf)]).unwrap(),
+        ));
+
+        let mut counter = NodeCounter::default();
+        counter.visit_type(&s);
+
+        // struct + typedef + its base integral + the plain integral = 4.
+        assert_eq!(counter.count, 4);
+    }
+
+    struct TypedefResolver;
+    impl SvTypeFolder for TypedefResolver {
+        fn fold_typedef(&mut self, ty: SvTypeTypedef) -> SvType {
+            self.fold_type(*ty.base_type)
+        }
+    }
+
+    #[test]
+    fn folder_strips_typedef_indirection() {
+        let leaf = ty_integral(false, false, Some(vec![(3, 0)]), None);
+        let named = ty_typedef(Some("nibble_t"), ty_typedef(Some("inner_t"), leaf));
+        let s = SvType::Struct(Box::new(
+            SvTypeStruct::new(None, None, true, vec![member("a", named)]).unwrap(),
+        ));
+
+        let mut resolver = TypedefResolver;
+        let folded = resolver.fold_type(s);
+
+        match folded {
+            SvType::Struct(s) => match &s.members[0].member_type {
+                SvType::Integral(_) => {}
+                other => panic!("expected typedef to be resolved away, got {:?}", other),
+            },
+            other => panic!("expected a struct, got {:?}", other),
+        }
+    }
+
+    // Example 1: twostate 1b: 1'b0 = False -> Some([0])
+    #[test]
+    fn literal_example_1() {
+        let ty = SvTypeIntegral::from_literal("1'b0").unwrap();
+        assert!(!ty.fourstate);
+        assert_eq!(ty.packed, None);
+        assert_eq!(ty.value, Some(vec![0]));
+    }
+
+    // Example 2: twostate 1b: 1'b1 = True -> Some([1])
+    #[test]
+    fn literal_example_2() {
+        let ty = SvTypeIntegral::from_literal("1'b1").unwrap();
+        assert!(!ty.fourstate);
+        assert_eq!(ty.value, Some(vec![1]));
+    }
+
+    // Example 5: fourstate 1b: 1'bX = Unknown -> Some([0,1])
+    #[test]
+    fn literal_example_5() {
+        let ty = SvTypeIntegral::from_literal("1'bX").unwrap();
+        assert!(ty.fourstate);
+        assert_eq!(ty.value, Some(vec![0, 1]));
+    }
+
+    // Example 6: fourstate 1b: 1'bZ = HighImpedence -> Some([1,1])
+    #[test]
+    fn literal_example_6() {
+        let ty = SvTypeIntegral::from_literal("1'bZ").unwrap();
+        assert!(ty.fourstate);
+        assert_eq!(ty.value, Some(vec![1, 1]));
+    }
+
+    // Example 7: twostate packed 5b: 5'd5 = 5'b00101 -> Some([5])
+    #[test]
+    fn literal_example_7() {
+        let ty = SvTypeIntegral::from_literal("5'd5").unwrap();
+        assert!(!ty.fourstate);
+        assert_eq!(ty.packed, Some(vec![(4, 0)]));
+        assert_eq!(ty.value, Some(vec![5]));
+    }
+
+    // Example 9: fourstate packed 5b: 5'd5 = 5'b01XZ0 -> Some([0xA, 0x6])
+    #[test]
+    fn literal_example_9() {
+        let ty = SvTypeIntegral::from_literal("5'b01XZ0").unwrap();
+        assert!(ty.fourstate);
+        assert_eq!(ty.value, Some(vec![0xA, 0x6]));
+    }
+
+    // 45'd123, twostate (no X/Z). With a 32-bit `usize` a 45b value takes
+    // two words (as in example 8 on `SvTypeIntegral::value`);
+    // `bits_to_value` packs using the real platform `usize` width, which
+    // fits 45 bits in a single word here.
+    #[test]
+    fn literal_decimal_wide() {
+        let ty = SvTypeIntegral::from_literal("45'd123").unwrap();
+        assert!(!ty.fourstate);
+        assert_eq!(ty.packed, Some(vec![(44, 0)]));
+        assert_eq!(ty.value, Some(vec![123]));
+    }
+
+    // A single unsized hex digit expands to fill the declared width,
+    // per the LRM's unsized-digit rule.
+    #[test]
+    fn literal_hex_z_expands_to_width() {
+        let ty = SvTypeIntegral::from_literal("8'hZ").unwrap();
+        assert!(ty.fourstate);
+        assert_eq!(ty.packed, Some(vec![(7, 0)]));
+        assert_eq!(ty.value, Some(vec![0xFF, 0xFF]));
+    }
+
+    #[test]
+    fn literal_underscores_are_ignored() {
+        let a = SvTypeIntegral::from_literal("8'b0000_1111").unwrap();
+        let b = SvTypeIntegral::from_literal("8'b00001111").unwrap();
+        assert_eq!(a.value, b.value);
+    }
+
+    #[test]
+    fn literal_signed_flag() {
+        let ty = SvTypeIntegral::from_literal("8'sb0").unwrap();
+        assert!(ty.signed);
+    }
+
+    #[test]
+    fn literal_decimal_with_xz_errors() {
+        let err = SvTypeIntegral::from_literal("4'dX").unwrap_err();
+        assert_eq!(err, SvLiteralError::DecimalWithXZ);
+    }
+
+    #[test]
+    fn literal_decimal_invalid_digit_errors() {
+        let err = SvTypeIntegral::from_literal("4'dA").unwrap_err();
+        assert_eq!(err, SvLiteralError::InvalidDigit('A'));
+    }
+
+    #[test]
+    fn literal_unknown_base_errors() {
+        let err = SvTypeIntegral::from_literal("4'q1010").unwrap_err();
+        assert_eq!(err, SvLiteralError::UnknownBase('q'));
+    }
+
+    #[test]
+    fn literal_round_trips() {
+        for literal in ["1'b0", "1'b1", "5'b01xz0", "45'd123", "8'hz"] {
+            let ty = SvTypeIntegral::from_literal(literal).unwrap();
+            let rendered = ty.to_literal();
+            let reparsed = SvTypeIntegral::from_literal(&rendered).unwrap();
+            assert_eq!(ty.value, reparsed.value, "round-trip of {literal} via {rendered}");
+            assert_eq!(ty.fourstate, reparsed.fourstate);
+        }
+    }
+
+    #[test]
+    fn literal_all_x_collapses() {
+        let ty = SvTypeIntegral::from_literal("8'hX").unwrap();
+        assert_eq!(ty.to_literal(), "8'bx");
+    }
 }